@@ -0,0 +1,179 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+
+use crate::core::matter::{decode_b64_count, encode_b64_count, Size};
+use crate::core::sizage::Sizage;
+use crate::error::Error;
+
+/// Count codes identify the kind and cardinality of a group of attachments
+/// that follow it in a CESR stream (e.g. a set of indexed signatures),
+/// rather than framing material of their own.
+#[derive(Debug, Eq, PartialEq)]
+pub enum CountCodex {
+    ControllerIdxSigs,
+    WitnessIdxSigs,
+    NonTransReceiptCouples,
+}
+
+impl CountCodex {
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            CountCodex::ControllerIdxSigs => "-A", // Group of indexed controller signatures.
+            CountCodex::WitnessIdxSigs => "-B", // Group of indexed witness signatures.
+            CountCodex::NonTransReceiptCouples => "-C", // Group of non-transferable receipt couples.
+        }
+    }
+
+    /// Looks up the codex variant whose hard part matches `code`, the
+    /// inverse of [`CountCodex::code`].
+    pub(crate) fn from_code(code: &str) -> Result<CountCodex, Error> {
+        match code {
+            "-A" => Ok(CountCodex::ControllerIdxSigs),
+            "-B" => Ok(CountCodex::WitnessIdxSigs),
+            "-C" => Ok(CountCodex::NonTransReceiptCouples),
+            _ => Err(Error::MatterError(format!("unknown count code '{code}'"))),
+        }
+    }
+}
+
+impl Size for CountCodex {
+    type Err = Error;
+
+    /// Every count code has a 2-character hard part and a 2-character
+    /// soft part holding the group's count, for a fixed 4-character frame;
+    /// count codes carry no material of their own, so `ls` is always `0`.
+    fn size(&self) -> Result<Sizage, Self::Err> {
+        match self.code() {
+            "-A" | "-B" | "-C" => Ok(Sizage::new(2, 2, 4, 0)),
+            code => Err(Error::MatterError(format!(
+                "no sizage known for count code '{code}'"
+            ))),
+        }
+    }
+}
+
+/// A count (group) code, framing the kind and number of attachments that
+/// immediately follow it in a CESR stream.
+#[derive(Debug)]
+pub struct Counter {
+    pub code: &'static str,
+    pub count: u32,
+    pub qb64: Option<String>,
+    pub qb64b: Option<Vec<u8>>,
+    pub qb2: Option<Vec<u8>>,
+}
+
+impl Counter {
+    /// Builds a `Counter` from a code and count, deriving its qb64/qb2 text
+    /// and binary forms.
+    pub fn new(code: &'static str, count: u32) -> Result<Counter, Error> {
+        let sizage = CountCodex::from_code(code)?.size()?;
+        let soft = encode_b64_count(count, sizage.ss as usize)?;
+        let qb64 = format!("{code}{soft}");
+        let qb64b = qb64.as_bytes().to_vec();
+        let qb2 = URL_SAFE_NO_PAD
+            .decode(qb64.as_bytes())
+            .map_err(|e| Error::MatterError(format!("invalid qb64 while converting to qb2: {e}")))?;
+
+        Ok(Counter {
+            code,
+            count,
+            qb64: Some(qb64),
+            qb64b: Some(qb64b),
+            qb2: Some(qb2),
+        })
+    }
+
+    /// Recovers a `Counter` from its qb64 text representation, returning it
+    /// alongside the number of characters consumed (always `fs`, since
+    /// count codes carry no variable-length material).
+    ///
+    /// Works over bytes and checks ASCII-ness before slicing, rather than
+    /// indexing `qb64` by a byte count derived from the code: a raw byte
+    /// offset into a `&str` panics if it doesn't land on a char boundary,
+    /// which arbitrary/malformed wire input could otherwise trigger.
+    pub fn from_qb64(qb64: &str) -> Result<(Counter, usize), Error> {
+        let bytes = qb64.as_bytes();
+        if bytes.len() < 2 || !bytes[..2].is_ascii() {
+            return Err(Error::MatterError("qb64 too short for a count code".into()));
+        }
+        let hard = std::str::from_utf8(&bytes[..2])
+            .map_err(|e| Error::MatterError(format!("invalid utf-8 in count code: {e}")))?;
+        let sizage = CountCodex::from_code(hard)?.size()?;
+
+        let hs = sizage.hs as usize;
+        let cs = hs + sizage.ss as usize;
+        if bytes.len() < cs || !bytes[..cs].is_ascii() {
+            return Err(Error::MatterError(
+                "qb64 too short for count code's soft part".into(),
+            ));
+        }
+
+        let fs = sizage.fs as usize;
+        if bytes.len() < fs {
+            return Err(Error::MatterError(format!(
+                "qb64 too short for count code: need {fs} chars, got {}",
+                bytes.len()
+            )));
+        }
+
+        let code = CountCodex::from_code(hard)?.code();
+        let soft = std::str::from_utf8(&bytes[hs..cs])
+            .map_err(|e| Error::MatterError(format!("invalid utf-8 in count code soft part: {e}")))?;
+        let count = decode_b64_count(soft)?;
+
+        let counter = Counter::new(code, count)?;
+        Ok((counter, fs))
+    }
+
+    /// Recovers a `Counter` from its qb2 binary representation. A count
+    /// code's 4-character frame is exactly one Base64 quadlet (3 bytes), so
+    /// the whole frame can be re-encoded as qb64 text in one step.
+    pub fn from_qb2(qb2: &[u8]) -> Result<(Counter, usize), Error> {
+        if qb2.len() < 3 {
+            return Err(Error::MatterError("qb2 too short for a count code".into()));
+        }
+        let qb64 = URL_SAFE_NO_PAD.encode(&qb2[..3]);
+        let (counter, _fs) = Counter::from_qb64(&qb64)?;
+        Ok((counter, 3))
+    }
+}
+
+#[cfg(test)]
+mod counter_tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_round_trips_through_qb64() {
+        let counter = Counter::new(CountCodex::ControllerIdxSigs.code(), 3).unwrap();
+        let (restored, consumed) = Counter::from_qb64(counter.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(consumed, 4);
+        assert_eq!(restored.code, counter.code);
+        assert_eq!(restored.count, 3);
+    }
+
+    #[test]
+    fn test_counter_round_trips_through_qb2() {
+        let counter = Counter::new(CountCodex::WitnessIdxSigs.code(), 5).unwrap();
+        let (restored, consumed) = Counter::from_qb2(counter.qb2.as_ref().unwrap()).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(restored.code, counter.code);
+        assert_eq!(restored.count, 5);
+    }
+
+    #[test]
+    fn test_counter_rejects_unknown_code() {
+        assert!(Counter::new("-Z", 1).is_err());
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_short_input() {
+        assert!(Counter::from_qb64("-A").is_err());
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_non_ascii_hard_part_instead_of_panicking() {
+        // "-" + a multi-byte UTF-8 character right after the code's leading
+        // byte must error, not panic on a non-char-boundary slice.
+        assert!(Counter::from_qb64("-\u{20ac}").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use ed25519_dalek::{Signature, Signer as DalekSigner, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+
+use crate::core::matter::{Matter, MatterCodex};
+use crate::error::Error;
+
+/// An Ed25519 signing key (code `A`), paired with the `Verfer` for its
+/// public half.
+pub struct Signer {
+    pub matter: Matter,
+    pub verfer: Verfer,
+}
+
+impl Signer {
+    /// Generates a fresh random Ed25519 keypair.
+    pub fn new_random() -> Result<Signer, Error> {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let matter = Matter::from_raw(
+            signing_key.to_bytes().to_vec(),
+            MatterCodex::Ed25519Seed.code(),
+        )?;
+        let verfer = Verfer::from_raw(
+            signing_key.verifying_key().to_bytes().to_vec(),
+            MatterCodex::Ed25519.code(),
+        )?;
+        Ok(Signer { matter, verfer })
+    }
+
+    /// Restores a `Signer` from the qb64 text of its seed.
+    pub fn from_qb64(qb64: &str) -> Result<Signer, Error> {
+        let matter = Matter::from_qb64(qb64)?;
+        let signing_key = signing_key(&matter)?;
+        let verfer = Verfer::from_raw(
+            signing_key.verifying_key().to_bytes().to_vec(),
+            MatterCodex::Ed25519.code(),
+        )?;
+        Ok(Signer { matter, verfer })
+    }
+
+    /// Produces a detached Ed25519 signature over `msg`.
+    pub fn sign(&self, msg: &[u8]) -> Result<Cigar, Error> {
+        let signing_key = signing_key(&self.matter)?;
+        let signature = signing_key.sign(msg);
+        Cigar::from_raw(signature.to_bytes().to_vec())
+    }
+}
+
+fn signing_key(matter: &Matter) -> Result<SigningKey, Error> {
+    let raw = matter
+        .raw
+        .as_ref()
+        .ok_or_else(|| Error::MatterError("signer has no raw seed".into()))?;
+    let bytes: [u8; 32] = raw
+        .as_slice()
+        .try_into()
+        .map_err(|_| Error::MatterError("ed25519 seed must be 32 bytes".into()))?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+/// An Ed25519 verification key, either transferable (code `D`) or
+/// non-transferable (code `B`).
+pub struct Verfer {
+    pub matter: Matter,
+}
+
+impl Verfer {
+    /// Builds a `Verfer` tagged with `code`, which must be
+    /// [`MatterCodex::Ed25519`] or [`MatterCodex::Ed25519N`].
+    pub fn from_raw(raw: Vec<u8>, code: &'static str) -> Result<Verfer, Error> {
+        if code != MatterCodex::Ed25519.code() && code != MatterCodex::Ed25519N.code() {
+            return Err(Error::MatterError(format!(
+                "code '{code}' is not a valid Verfer code"
+            )));
+        }
+        Ok(Verfer {
+            matter: Matter::from_raw(raw, code)?,
+        })
+    }
+
+    pub fn from_qb64(qb64: &str) -> Result<Verfer, Error> {
+        Ok(Verfer {
+            matter: Matter::from_qb64(qb64)?,
+        })
+    }
+
+    /// Verifies that `sig` is a valid Ed25519 signature over `msg` by this key.
+    pub fn verify(&self, sig: &Cigar, msg: &[u8]) -> Result<bool, Error> {
+        let verifying_key = verifying_key(&self.matter)?;
+        let sig_bytes: [u8; 64] = sig
+            .matter
+            .raw
+            .as_ref()
+            .ok_or_else(|| Error::MatterError("cigar has no raw signature".into()))?
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::MatterError("ed25519 signature must be 64 bytes".into()))?;
+        let signature = Signature::from_bytes(&sig_bytes);
+        Ok(verifying_key.verify(msg, &signature).is_ok())
+    }
+}
+
+fn verifying_key(matter: &Matter) -> Result<VerifyingKey, Error> {
+    let raw = matter
+        .raw
+        .as_ref()
+        .ok_or_else(|| Error::MatterError("verfer has no raw key".into()))?;
+    let bytes: [u8; 32] = raw
+        .as_slice()
+        .try_into()
+        .map_err(|_| Error::MatterError("ed25519 verification key must be 32 bytes".into()))?;
+    VerifyingKey::from_bytes(&bytes)
+        .map_err(|e| Error::MatterError(format!("invalid ed25519 verification key: {e}")))
+}
+
+/// A non-indexed, detached Ed25519 signature (code `0B`), used to sign
+/// arbitrary data outside of a controller's indexed key set.
+pub struct Cigar {
+    pub matter: Matter,
+}
+
+impl Cigar {
+    pub fn from_raw(raw: Vec<u8>) -> Result<Cigar, Error> {
+        Ok(Cigar {
+            matter: Matter::from_raw(raw, MatterCodex::Ed25519Sig.code())?,
+        })
+    }
+
+    pub fn from_qb64(qb64: &str) -> Result<Cigar, Error> {
+        Ok(Cigar {
+            matter: Matter::from_qb64(qb64)?,
+        })
+    }
+}
+
+/// An Ed25519 signature (code `0B`) as attached in a group of controller
+/// signatures. Carries the same signature material and code as `Cigar`;
+/// it stores no index of the signing key, and no other type in this crate
+/// records one.
+pub struct Siger {
+    pub matter: Matter,
+}
+
+impl Siger {
+    pub fn from_raw(raw: Vec<u8>) -> Result<Siger, Error> {
+        Ok(Siger {
+            matter: Matter::from_raw(raw, MatterCodex::Ed25519Sig.code())?,
+        })
+    }
+
+    pub fn from_qb64(qb64: &str) -> Result<Siger, Error> {
+        Ok(Siger {
+            matter: Matter::from_qb64(qb64)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod keys_tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signer = Signer::new_random().unwrap();
+        let msg = b"hello cesr";
+        let cigar = signer.sign(msg).unwrap();
+        assert!(signer.verfer.verify(&cigar, msg).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_message() {
+        let signer = Signer::new_random().unwrap();
+        let cigar = signer.sign(b"hello cesr").unwrap();
+        assert!(!signer.verfer.verify(&cigar, b"goodbye cesr").unwrap());
+    }
+
+    #[test]
+    fn test_signer_round_trips_through_qb64() {
+        let signer = Signer::new_random().unwrap();
+        let restored = Signer::from_qb64(signer.matter.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(restored.matter.raw, signer.matter.raw);
+        assert_eq!(
+            restored.verfer.matter.qb64.as_ref().unwrap(),
+            signer.verfer.matter.qb64.as_ref().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verfer_supports_transferable_and_nontransferable_codes() {
+        let raw = vec![3u8; 32];
+        let transferable = Verfer::from_raw(raw.clone(), MatterCodex::Ed25519.code()).unwrap();
+        let nontransferable = Verfer::from_raw(raw, MatterCodex::Ed25519N.code()).unwrap();
+        assert!(transferable
+            .matter
+            .qb64
+            .unwrap()
+            .starts_with(MatterCodex::Ed25519.code()));
+        assert!(nontransferable
+            .matter
+            .qb64
+            .unwrap()
+            .starts_with(MatterCodex::Ed25519N.code()));
+    }
+
+    #[test]
+    fn test_verfer_rejects_non_verfer_code() {
+        assert!(Verfer::from_raw(vec![3u8; 32], MatterCodex::Ed25519Seed.code()).is_err());
+    }
+
+    #[test]
+    fn test_cigar_qb64_has_signature_code() {
+        let signer = Signer::new_random().unwrap();
+        let cigar = signer.sign(b"hello cesr").unwrap();
+        assert!(cigar
+            .matter
+            .qb64
+            .as_ref()
+            .unwrap()
+            .starts_with(MatterCodex::Ed25519Sig.code()));
+    }
+}
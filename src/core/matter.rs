@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use lazy_static::lazy_static;
 
 use crate::core::sizage::Sizage;
@@ -17,6 +18,12 @@ pub enum MatterCodex {
     X25519CipherSalt,
     Salt128,
     Ed25519Sig,
+    Bytes,
+    BytesL1,
+    BytesL2,
+    StrB64,
+    StrB64L1,
+    StrB64L2,
 }
 
 impl MatterCodex {
@@ -32,6 +39,37 @@ impl MatterCodex {
             MatterCodex::X25519CipherSalt => "1AAH", // X25519 100 char b64 Cipher of 24 char qb64 Salt
             MatterCodex::Salt128 => "0A", // 128 bit random salt or 128 bit number (see Huge)
             MatterCodex::Ed25519Sig => "0B", // Ed25519 signature.
+            MatterCodex::Bytes => "4A", // Variable length raw bytes, quadlet soft count, 0 lead bytes.
+            MatterCodex::BytesL1 => "4B", // Variable length raw bytes, quadlet soft count, 1 lead byte.
+            MatterCodex::BytesL2 => "4C", // Variable length raw bytes, quadlet soft count, 2 lead bytes.
+            MatterCodex::StrB64 => "6A", // Variable length Base64URL string, quadlet soft count, 0 lead bytes.
+            MatterCodex::StrB64L1 => "6B", // Variable length Base64URL string, quadlet soft count, 1 lead byte.
+            MatterCodex::StrB64L2 => "6C", // Variable length Base64URL string, quadlet soft count, 2 lead bytes.
+        }
+    }
+
+    /// Looks up the codex variant whose hard part matches `code`, the
+    /// inverse of [`MatterCodex::code`]. Used to recover a `&'static str`
+    /// code (and its `Sizage`) from text read off the wire.
+    pub(crate) fn from_code(code: &str) -> Result<MatterCodex, Error> {
+        match code {
+            "A" => Ok(MatterCodex::Ed25519Seed),
+            "B" => Ok(MatterCodex::Ed25519N),
+            "C" => Ok(MatterCodex::X25519),
+            "D" => Ok(MatterCodex::Ed25519),
+            "E" => Ok(MatterCodex::Blake3_256),
+            "O" => Ok(MatterCodex::X25519Private),
+            "P" => Ok(MatterCodex::X25519CipherSeed),
+            "1AAH" => Ok(MatterCodex::X25519CipherSalt),
+            "0A" => Ok(MatterCodex::Salt128),
+            "0B" => Ok(MatterCodex::Ed25519Sig),
+            "4A" => Ok(MatterCodex::Bytes),
+            "4B" => Ok(MatterCodex::BytesL1),
+            "4C" => Ok(MatterCodex::BytesL2),
+            "6A" => Ok(MatterCodex::StrB64),
+            "6B" => Ok(MatterCodex::StrB64L1),
+            "6C" => Ok(MatterCodex::StrB64L2),
+            _ => Err(Error::MatterError(format!("unknown matter code '{code}'"))),
         }
     }
 }
@@ -64,6 +102,461 @@ impl Matter {
             strip: Some(strip),
         }
     }
+
+    /// Derives the qb64 text representation from raw material and a code,
+    /// the `_infil` side of the CESR text transform: lead and pad zero
+    /// bytes are prepended to `raw` so that the Base64URL encoding lines
+    /// up on a 24-bit boundary, then the pad characters introduced by
+    /// that alignment are stripped and replaced by the code itself.
+    pub fn from_raw(raw: Vec<u8>, code: &'static str) -> Result<Matter, Error> {
+        let sizage = MatterCodex::from_code(code)?.size()?;
+        if sizage.ss != 0 {
+            return Err(Error::MatterError(format!(
+                "code '{code}' has a variable soft size, use from_raw_var instead"
+            )));
+        }
+
+        let qb64 = encode_qb64(&raw, code, &sizage)?;
+        let qb64b = qb64.as_bytes().to_vec();
+        let qb2 = Matter::to_qb2_from_qb64(&qb64)?;
+
+        Ok(Matter {
+            raw: Some(raw),
+            code,
+            qb64b: Some(qb64b),
+            qb64: Some(qb64),
+            qb2: Some(qb2),
+            strip: Some(false),
+        })
+    }
+
+    /// Derives the qb64 text representation of a variable-length primitive,
+    /// whose code carries a Base64-encoded quadlet count (`ss > 0`) instead
+    /// of implying a single fixed material length. `raw` need not be a
+    /// multiple of 3 bytes: enough lead zero bytes (0, 1, or 2) are
+    /// prepended to reach quadlet alignment, same as `from_raw` does for
+    /// fixed codes, and `code` is swapped for the sibling variant that
+    /// records how many lead bytes were used (see `ls_variant`).
+    pub fn from_raw_var(raw: Vec<u8>, code: &'static str) -> Result<Matter, Error> {
+        let sizage = MatterCodex::from_code(code)?.size()?;
+        if sizage.ss == 0 {
+            return Err(Error::MatterError(format!(
+                "code '{code}' has a fixed size, use from_raw instead"
+            )));
+        }
+
+        let ls = (3 - raw.len() % 3) % 3;
+        let code = ls_variant(code, ls)?;
+        let sizage = MatterCodex::from_code(code)?.size()?;
+
+        let (qb64, _fs) = encode_qb64_var(&raw, code, &sizage)?;
+        let qb64b = qb64.as_bytes().to_vec();
+        let qb2 = Matter::to_qb2_from_qb64(&qb64)?;
+
+        Ok(Matter {
+            raw: Some(raw),
+            code,
+            qb64b: Some(qb64b),
+            qb64: Some(qb64),
+            qb2: Some(qb2),
+            strip: Some(false),
+        })
+    }
+
+    /// Recovers a `Matter` from its qb64 text representation, the `_exfil`
+    /// side of the CESR text transform: the code is identified from the
+    /// leading character via `HARDS`, the `Sizage` it implies is used to
+    /// slice out the material, and the lead/pad zero bytes that transform
+    /// introduced are validated and dropped to recover the raw bytes. Codes
+    /// with a variable soft size (`ss > 0`) first read their quadlet count
+    /// out of the soft part to learn the frame size.
+    pub fn from_qb64(qb64: &str) -> Result<Matter, Error> {
+        let (code, sizage) = sniff_code(qb64)?;
+        let (raw, fs) = if sizage.ss == 0 {
+            let fs = sizage.fs as usize;
+            let bytes = qb64.as_bytes();
+            if bytes.len() < fs || !bytes[..fs].is_ascii() {
+                return Err(Error::MatterError(format!(
+                    "qb64 too short: need {fs} chars, got {}",
+                    bytes.len()
+                )));
+            }
+            let frame = std::str::from_utf8(&bytes[..fs]).expect("ASCII checked above");
+            (decode_qb64(frame, code, &sizage)?, fs)
+        } else {
+            decode_qb64_var(qb64, code, &sizage)?
+        };
+
+        // `fs` is known ASCII-only up to this point for both branches (the
+        // fixed branch just checked it above; decode_qb64_var checks its own
+        // code+soft and material spans), so this slice can't land mid-char.
+        let qb64 = qb64[..fs].to_string();
+        let qb64b = qb64.as_bytes().to_vec();
+        let qb2 = Matter::to_qb2_from_qb64(&qb64)?;
+
+        Ok(Matter {
+            raw: Some(raw),
+            code,
+            qb64b: Some(qb64b),
+            qb64: Some(qb64),
+            qb2: Some(qb2),
+            strip: Some(false),
+        })
+    }
+
+    /// Recovers a `Matter` from its qb2 binary representation, the binary
+    /// counterpart of [`Matter::from_qb64`]: the leading bytes are
+    /// re-encoded as Base64URL just far enough to identify the code via
+    /// `HARDS`, its `Sizage` gives the total qb2 length (`fs` qb64 chars is
+    /// `fs*3/4` bytes), and the framed bytes are decoded through the same
+    /// text transform used by `from_qb64`. Codes with a variable soft size
+    /// (`ss > 0`) first re-encode just the code+soft part to learn the
+    /// quadlet count before the total frame length is known.
+    pub fn from_qb2(qb2: &[u8]) -> Result<Matter, Error> {
+        if qb2.is_empty() {
+            return Err(Error::MatterError("empty qb2 buffer".into()));
+        }
+
+        let probe_len = qb2.len().min(4);
+        let probe = URL_SAFE_NO_PAD.encode(&qb2[..probe_len]);
+        let first = probe
+            .chars()
+            .next()
+            .ok_or_else(|| Error::MatterError("empty qb2 buffer".into()))?;
+        let hs = *HARDS
+            .get(&first)
+            .ok_or_else(|| Error::MatterError(format!("unknown code start '{first}'")))?
+            as usize;
+        if probe.len() < hs {
+            return Err(Error::MatterError("qb2 too short to identify its code".into()));
+        }
+
+        let codex = MatterCodex::from_code(&probe[..hs])?;
+        let sizage = codex.size()?;
+        let code = codex.code();
+
+        let fs = if sizage.ss == 0 {
+            sizage.fs as usize
+        } else {
+            let cs = (sizage.hs + sizage.ss) as usize;
+            let cs_bytes = cs * 3 / 4;
+            if qb2.len() < cs_bytes {
+                return Err(Error::MatterError(format!(
+                    "qb2 too short for code '{code}': need {cs_bytes} bytes to read its soft count, got {}",
+                    qb2.len()
+                )));
+            }
+            let cs_qb64 = Matter::to_qb64_from_qb2(&qb2[..cs_bytes])?;
+            let count = decode_b64_count(&cs_qb64[hs..cs])?;
+            cs + (count as usize) * 4
+        };
+        let total_bytes = fs * 3 / 4;
+        if qb2.len() < total_bytes {
+            return Err(Error::MatterError(format!(
+                "qb2 too short for code '{code}': need {total_bytes} bytes, got {}",
+                qb2.len()
+            )));
+        }
+
+        let framed = &qb2[..total_bytes];
+        let qb64 = Matter::to_qb64_from_qb2(framed)?;
+        let raw = if sizage.ss == 0 {
+            decode_qb64(&qb64, code, &sizage)?
+        } else {
+            decode_qb64_var(&qb64, code, &sizage)?.0
+        };
+
+        Ok(Matter {
+            raw: Some(raw),
+            code,
+            qb64b: Some(qb64.as_bytes().to_vec()),
+            qb64: Some(qb64),
+            qb2: Some(framed.to_vec()),
+            strip: Some(false),
+        })
+    }
+
+    /// Serializes this primitive's qb64 text into its qb2 binary form.
+    pub fn qb2(&self) -> Result<Vec<u8>, Error> {
+        let qb64 = self
+            .qb64
+            .as_deref()
+            .ok_or_else(|| Error::MatterError("matter has no qb64 text to derive qb2 from".into()))?;
+        Matter::to_qb2_from_qb64(qb64)
+    }
+
+    /// Converts a qb2 binary buffer to its qb64 text form. The buffer must
+    /// hold exactly one framed primitive (as returned by [`Matter::from_qb2`]).
+    pub fn to_qb64_from_qb2(qb2: &[u8]) -> Result<String, Error> {
+        Ok(URL_SAFE_NO_PAD.encode(qb2))
+    }
+
+    /// Converts a qb64 text primitive to its qb2 binary form.
+    pub fn to_qb2_from_qb64(qb64: &str) -> Result<Vec<u8>, Error> {
+        URL_SAFE_NO_PAD
+            .decode(qb64.as_bytes())
+            .map_err(|e| Error::MatterError(format!("invalid qb64 while converting to qb2: {e}")))
+    }
+}
+
+/// Identifies the code at the front of `qb64`, using `HARDS` to learn the
+/// hard size from the leading character before the code text itself is
+/// known.
+///
+/// Works over bytes and checks ASCII-ness before slicing, rather than
+/// indexing `qb64` by a byte count derived from `HARDS`: a raw byte offset
+/// into a `&str` panics if it doesn't land on a char boundary, which
+/// arbitrary/malformed wire input could otherwise trigger.
+fn sniff_code(qb64: &str) -> Result<(&'static str, Sizage), Error> {
+    let bytes = qb64.as_bytes();
+    let first = *bytes
+        .first()
+        .ok_or_else(|| Error::MatterError("empty qb64 string".into()))? as char;
+    let hs = *HARDS
+        .get(&first)
+        .ok_or_else(|| Error::MatterError(format!("unknown code start '{first}'")))?
+        as usize;
+    if bytes.len() < hs || !bytes[..hs].is_ascii() {
+        return Err(Error::MatterError("qb64 too short for its code".into()));
+    }
+
+    let hard = std::str::from_utf8(&bytes[..hs])
+        .map_err(|e| Error::MatterError(format!("invalid utf-8 in qb64 code: {e}")))?;
+    let codex = MatterCodex::from_code(hard)?;
+    let sizage = codex.size()?;
+    Ok((codex.code(), sizage))
+}
+
+/// Encodes `raw` under `code`/`sizage` into qb64 text, honoring the code's
+/// lead size (`ls`) but not a variable soft size (`ss` must be `0`; see
+/// `encode_qb64_var` for `ss > 0`).
+fn encode_qb64(raw: &[u8], code: &'static str, sizage: &Sizage) -> Result<String, Error> {
+    let cs = (sizage.hs + sizage.ss) as usize;
+    let ls = sizage.ls as usize;
+    let ps = (3 - (raw.len() + ls) % 3) % 3;
+
+    let mut padded = vec![0u8; ps];
+    padded.extend(std::iter::repeat_n(0u8, ls));
+    padded.extend_from_slice(raw);
+
+    let full = URL_SAFE_NO_PAD.encode(&padded);
+    let stripped = &full[ps..];
+
+    if cs + stripped.len() != sizage.fs as usize {
+        return Err(Error::MatterError(format!(
+            "raw of {} bytes does not fit code '{code}': expected fs {}, got {}",
+            raw.len(),
+            sizage.fs,
+            cs + stripped.len()
+        )));
+    }
+
+    Ok(format!("{code}{stripped}"))
+}
+
+/// Decodes the material following `code` in `qb64` (already truncated to
+/// `sizage.fs` chars) back into raw bytes, validating that the bits
+/// contributed by the lead/pad zero bytes are in fact zero.
+fn decode_qb64(qb64: &str, code: &'static str, sizage: &Sizage) -> Result<Vec<u8>, Error> {
+    let cs = (sizage.hs + sizage.ss) as usize;
+    let fs = sizage.fs as usize;
+    let ls = sizage.ls as usize;
+
+    let stripped_len = fs - cs;
+    let ps = (4 - stripped_len % 4) % 4;
+
+    let material = &qb64[cs..fs];
+    let full = format!("{}{material}", "A".repeat(ps));
+    let decoded = URL_SAFE_NO_PAD
+        .decode(full.as_bytes())
+        .map_err(|e| Error::MatterError(format!("invalid base64 material for '{code}': {e}")))?;
+
+    let skip = ls + ps;
+    if decoded.len() < skip {
+        return Err(Error::MatterError(format!(
+            "decoded material for '{code}' shorter than its lead and pad bytes"
+        )));
+    }
+    if decoded[..skip].iter().any(|b| *b != 0) {
+        return Err(Error::MatterError(format!(
+            "non-zero bits in stripped lead/pad bytes for '{code}'"
+        )));
+    }
+
+    Ok(decoded[skip..].to_vec())
+}
+
+/// The Base64URL alphabet, in digit order, used to encode/decode the
+/// quadlet count carried in a variable-length code's soft part.
+const B64_DIGITS: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes `count` as a fixed-width, big-endian run of `width` Base64URL
+/// digits, as used by a variable-length code's soft part.
+pub(crate) fn encode_b64_count(count: u32, width: usize) -> Result<String, Error> {
+    if width > 0 && (count as u64) >= 1u64 << (6 * width) {
+        return Err(Error::MatterError(format!(
+            "count {count} does not fit in {width} base64 digits"
+        )));
+    }
+    let mut digits = vec![0u8; width];
+    let mut n = count;
+    for digit in digits.iter_mut().rev() {
+        *digit = B64_DIGITS[(n & 0x3f) as usize];
+        n >>= 6;
+    }
+    Ok(String::from_utf8(digits).expect("B64_DIGITS is all ASCII"))
+}
+
+/// Decodes a run of Base64URL digits (big-endian) into the count it
+/// represents, the inverse of `encode_b64_count`.
+pub(crate) fn decode_b64_count(digits: &str) -> Result<u32, Error> {
+    let mut n: u64 = 0;
+    for c in digits.chars() {
+        let v = B64_DIGITS
+            .iter()
+            .position(|&d| d as char == c)
+            .ok_or_else(|| Error::MatterError(format!("invalid base64 count digit '{c}'")))?;
+        n = (n << 6) | v as u64;
+    }
+    u32::try_from(n).map_err(|_| Error::MatterError(format!("count '{digits}' overflows u32")))
+}
+
+/// Looks up the sibling of `code` whose lead size is `ls`, the mechanism
+/// [`Matter::from_raw_var`] uses to align raw material of any length to a
+/// quadlet boundary: each variable-length code family (e.g. `Bytes`,
+/// `StrB64`) has three members, one per possible lead size (0, 1, or 2
+/// bytes), and the wire code itself records which was used so decoding
+/// needs no extra state.
+fn ls_variant(code: &str, ls: usize) -> Result<&'static str, Error> {
+    match (code, ls) {
+        ("4A", 0) => Ok("4A"),
+        ("4A", 1) => Ok("4B"),
+        ("4A", 2) => Ok("4C"),
+        ("6A", 0) => Ok("6A"),
+        ("6A", 1) => Ok("6B"),
+        ("6A", 2) => Ok("6C"),
+        _ => Err(Error::MatterError(format!(
+            "no {ls}-lead-byte variant of code '{code}'"
+        ))),
+    }
+}
+
+/// Encodes `raw` under a variable-length `code`/`sizage` (`ss > 0`) into
+/// qb64 text, returning the text and its frame size. Because the code's
+/// hard+soft size is quadlet-aligned, no pad-character stripping is
+/// needed as it is for fixed codes; instead `raw` (plus `sizage.ls` lead
+/// bytes) must already be a multiple of 3 bytes, which
+/// [`Matter::from_raw_var`] guarantees by picking the right `ls_variant`.
+fn encode_qb64_var(raw: &[u8], code: &'static str, sizage: &Sizage) -> Result<(String, usize), Error> {
+    let cs = (sizage.hs + sizage.ss) as usize;
+    if !cs.is_multiple_of(4) {
+        return Err(Error::MatterError(format!(
+            "variable code '{code}' must have a quadlet-aligned head (hs + ss must be a multiple of 4)"
+        )));
+    }
+
+    let ls = sizage.ls as usize;
+    if !(raw.len() + ls).is_multiple_of(3) {
+        return Err(Error::MatterError(format!(
+            "raw of {} bytes (plus {ls} lead bytes) for code '{code}' is not a multiple of 3 bytes",
+            raw.len()
+        )));
+    }
+
+    let mut padded = vec![0u8; ls];
+    padded.extend_from_slice(raw);
+    let encoded = URL_SAFE_NO_PAD.encode(&padded);
+    let count = u32::try_from(padded.len() / 3)
+        .map_err(|_| Error::MatterError(format!("raw for code '{code}' is too large to frame")))?;
+    let soft = encode_b64_count(count, sizage.ss as usize)?;
+    let fs = cs + (count as usize) * 4;
+
+    Ok((format!("{code}{soft}{encoded}"), fs))
+}
+
+/// Decodes a variable-length primitive (`ss > 0`) out of the front of
+/// `qb64`, reading its quadlet count from the soft part to learn the
+/// frame size before decoding the material, the counterpart of
+/// `encode_qb64_var`. Returns the raw bytes and the frame size consumed.
+///
+/// Works over bytes and checks ASCII-ness before slicing, rather than
+/// indexing `qb64` by a byte count derived from the code: a raw byte
+/// offset into a `&str` panics if it doesn't land on a char boundary,
+/// which arbitrary/malformed wire input could otherwise trigger.
+fn decode_qb64_var(qb64: &str, code: &'static str, sizage: &Sizage) -> Result<(Vec<u8>, usize), Error> {
+    let bytes = qb64.as_bytes();
+    let hs = sizage.hs as usize;
+    let cs = hs + sizage.ss as usize;
+    if bytes.len() < cs || !bytes[..cs].is_ascii() {
+        return Err(Error::MatterError(format!(
+            "qb64 too short for code '{code}' soft part"
+        )));
+    }
+
+    let soft = std::str::from_utf8(&bytes[hs..cs]).expect("ASCII checked above");
+    let count = decode_b64_count(soft)?;
+    let fs = cs + (count as usize) * 4;
+    if bytes.len() < fs || !bytes[cs..fs].is_ascii() {
+        return Err(Error::MatterError(format!(
+            "qb64 too short for code '{code}': need {fs} chars, got {}",
+            bytes.len()
+        )));
+    }
+
+    let material = std::str::from_utf8(&bytes[cs..fs]).expect("ASCII checked above");
+    let decoded = URL_SAFE_NO_PAD
+        .decode(material.as_bytes())
+        .map_err(|e| Error::MatterError(format!("invalid base64 material for '{code}': {e}")))?;
+
+    let ls = sizage.ls as usize;
+    if decoded.len() < ls {
+        return Err(Error::MatterError(format!(
+            "decoded material for '{code}' shorter than its lead bytes"
+        )));
+    }
+    if decoded[..ls].iter().any(|b| *b != 0) {
+        return Err(Error::MatterError(format!(
+            "non-zero bits in lead bytes for '{code}'"
+        )));
+    }
+
+    Ok((decoded[ls..].to_vec(), fs))
+}
+
+/// Computes how many bytes of `bytes` make up one qb64 primitive, without
+/// requiring the rest of the buffer (which may hold further, differently
+/// encoded primitives) to be valid UTF-8. Code characters are always
+/// single-byte ASCII, so only the bytes needed to learn `hs`/`ss`/the
+/// soft count are decoded as UTF-8; callers then validate just that
+/// bounded prefix before handing it to [`Matter::from_qb64`].
+pub(crate) fn qb64_frame_len(bytes: &[u8]) -> Result<usize, Error> {
+    let first = *bytes
+        .first()
+        .ok_or_else(|| Error::MatterError("empty qb64 buffer".into()))? as char;
+    let hs = *HARDS
+        .get(&first)
+        .ok_or_else(|| Error::MatterError(format!("unknown code start '{first}'")))?
+        as usize;
+    if bytes.len() < hs {
+        return Err(Error::MatterError("qb64 too short for its code".into()));
+    }
+
+    let hard = std::str::from_utf8(&bytes[..hs])
+        .map_err(|e| Error::MatterError(format!("invalid utf-8 in qb64 code: {e}")))?;
+    let sizage = MatterCodex::from_code(hard)?.size()?;
+    if sizage.ss == 0 {
+        return Ok(sizage.fs as usize);
+    }
+
+    let cs = (sizage.hs + sizage.ss) as usize;
+    if bytes.len() < cs {
+        return Err(Error::MatterError("qb64 too short for its soft count".into()));
+    }
+    let soft = std::str::from_utf8(&bytes[hs..cs])
+        .map_err(|e| Error::MatterError(format!("invalid utf-8 in qb64 soft count: {e}")))?;
+    let count = decode_b64_count(soft)?;
+    Ok(cs + (count as usize) * 4)
 }
 
 impl Default for Matter {
@@ -96,7 +589,24 @@ impl Size for MatterCodex {
             "P" => Ok(Sizage::new(1, 0, 124, 0)),
             "1AAH" => Ok(Sizage::new(2, 0, 24, 0)),
             "0A" => Ok(Sizage::new(1, 0, 88, 0)),
-            "0B" => Ok(Sizage::new(4, 0, 100, 0)),
+            // hs=2 matches HARDS['0'] and fs=88 frames a 64-byte Ed25519
+            // signature (64 bytes plus 2 pad bytes -> 88 b64 chars, whose 2
+            // leading pad chars are replaced by the 2-char code).
+            // Originally shipped here as (hs=4, fs=100), which neither
+            // matched HARDS nor fit a 64-byte signature; every "0B" round
+            // trip through encode_qb64/decode_qb64 would have failed.
+            "0B" => Ok(Sizage::new(2, 0, 88, 0)),
+            // Variable-length codes: ss holds a quadlet count, so fs is not
+            // fixed and is reported as 0 here; it is computed per-instance
+            // from the soft part as hs + ss + count * 4. Each comes in three
+            // lead-byte variants (ls 0/1/2) so raw material of any length,
+            // not just multiples of 3 bytes, can be quadlet-aligned.
+            "4A" => Ok(Sizage::new(2, 2, 0, 0)),
+            "4B" => Ok(Sizage::new(2, 2, 0, 1)),
+            "4C" => Ok(Sizage::new(2, 2, 0, 2)),
+            "6A" => Ok(Sizage::new(2, 2, 0, 0)),
+            "6B" => Ok(Sizage::new(2, 2, 0, 1)),
+            "6C" => Ok(Sizage::new(2, 2, 0, 2)),
             _ => Err(Error::MatterError("Unknown code".into())),
         }
     }
@@ -106,7 +616,7 @@ impl Size for MatterCodex {
 
 lazy_static! {
     #[rustfmt::skip]
-    static ref HARDS: HashMap<char, u16> = [
+    pub(crate) static ref HARDS: HashMap<char, u16> = [
         ('A', 1), ('B', 1), ('C', 1), ('D', 1), ('E', 1), ('F', 1), ('G', 1),
         ('H', 1), ('I', 1), ('J', 1), ('K', 1), ('L', 1), ('M', 1), ('N', 1), ('O', 1), ('P', 1), ('Q', 1), ('R', 1),
         ('S', 1), ('T', 1), ('U', 1), ('V', 1), ('W', 1), ('X', 1), ('Y', 1), ('Z', 1), ('a', 1), ('b', 1), ('c', 1),
@@ -191,9 +701,9 @@ mod matter_codex_tests {
         assert_eq!(s.ls, 0);
 
         s = MatterCodex::Ed25519Sig.size().unwrap();
-        assert_eq!(s.hs, 4);
+        assert_eq!(s.hs, 2);
         assert_eq!(s.ss, 0);
-        assert_eq!(s.fs, 100);
+        assert_eq!(s.fs, 88);
         assert_eq!(s.ls, 0);
     }
 
@@ -233,6 +743,186 @@ mod matter_codex_tests {
         assert_eq!(m.qb64b.unwrap(), b"b".to_vec());
         assert_eq!(m.qb64.unwrap(), "qb64");
         assert_eq!(m.qb2.unwrap(), b"c".to_vec());
-        assert_eq!(m.strip.unwrap(), true);
+        assert!(m.strip.unwrap());
+    }
+
+    #[test]
+    fn test_from_raw_round_trips_through_qb64() {
+        let raw = vec![7u8; 32];
+        let m = Matter::from_raw(raw.clone(), MatterCodex::Ed25519.code()).unwrap();
+        assert_eq!(m.code, MatterCodex::Ed25519.code());
+        assert_eq!(m.qb64.as_ref().unwrap().len(), 44);
+        assert!(m.qb64.as_ref().unwrap().starts_with("D"));
+
+        let back = Matter::from_qb64(m.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+        assert_eq!(back.code, MatterCodex::Ed25519.code());
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_short_input() {
+        let err = Matter::from_qb64("D").unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_unknown_code() {
+        let err = Matter::from_qb64("9short").unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_multibyte_char_in_code_instead_of_panicking() {
+        // '1' implies a 4-byte hard part (HARDS['1'] == 4); "ab€" puts a
+        // multi-byte UTF-8 character right where that hard part would be
+        // sliced, which must error rather than panic on a non-char-boundary
+        // index.
+        let err = Matter::from_qb64("1ab€rest").unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_multibyte_char_in_material_instead_of_panicking() {
+        let raw = vec![9u8; 32];
+        let mut qb64 = Matter::from_raw(raw, MatterCodex::Ed25519.code())
+            .unwrap()
+            .qb64
+            .unwrap();
+        qb64.truncate(20);
+        qb64.push('€'); // lands inside the expected fixed-size material span
+        qb64.push_str("rest of the stream");
+
+        let err = Matter::from_qb64(&qb64).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_raw_rejects_wrong_length() {
+        let err = Matter::from_raw(vec![0u8; 3], MatterCodex::Ed25519.code()).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_raw_populates_qb2() {
+        let raw = vec![3u8; 32];
+        let m = Matter::from_raw(raw, MatterCodex::Ed25519.code()).unwrap();
+        assert_eq!(m.qb2.as_ref().unwrap(), &m.qb2().unwrap());
+        assert_eq!(
+            Matter::to_qb64_from_qb2(m.qb2.as_ref().unwrap()).unwrap(),
+            *m.qb64.as_ref().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_qb2_round_trips_through_from_qb2() {
+        let raw = vec![42u8; 32];
+        let m = Matter::from_raw(raw.clone(), MatterCodex::Ed25519.code()).unwrap();
+        let qb2 = m.qb2.clone().unwrap();
+
+        let back = Matter::from_qb2(&qb2).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+        assert_eq!(back.code, MatterCodex::Ed25519.code());
+        assert_eq!(back.qb64.unwrap(), m.qb64.unwrap());
+    }
+
+    #[test]
+    fn test_variable_code_round_trips_through_qb2() {
+        let raw = b"hello cesr variable bytes!!".to_vec(); // 27 bytes, already a multiple of 3
+        let m = Matter::from_raw_var(raw.clone(), MatterCodex::Bytes.code()).unwrap();
+        let qb2 = m.qb2.clone().unwrap();
+
+        let back = Matter::from_qb2(&qb2).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+        assert_eq!(back.code, MatterCodex::Bytes.code());
+        assert_eq!(back.qb64.unwrap(), m.qb64.unwrap());
+    }
+
+    #[test]
+    fn test_from_qb2_rejects_empty_buffer() {
+        let err = Matter::from_qb2(&[]).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb2_rejects_truncated_buffer() {
+        let raw = vec![1u8; 32];
+        let m = Matter::from_raw(raw, MatterCodex::Ed25519.code()).unwrap();
+        let qb2 = m.qb2.unwrap();
+        let err = Matter::from_qb2(&qb2[..qb2.len() - 1]).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_raw_var_round_trips_through_qb64() {
+        let raw = b"hello cesr variable bytes!!".to_vec(); // 27 bytes, already a multiple of 3
+        let m = Matter::from_raw_var(raw.clone(), MatterCodex::Bytes.code()).unwrap();
+        assert_eq!(m.code, MatterCodex::Bytes.code());
+        assert!(m.qb64.as_ref().unwrap().starts_with("4A"));
+
+        let back = Matter::from_qb64(m.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+    }
+
+    #[test]
+    fn test_from_raw_var_encodes_quadlet_count() {
+        let raw = vec![9u8; 9]; // 3 quadlets
+        let m = Matter::from_raw_var(raw, MatterCodex::StrB64.code()).unwrap();
+        // code (2 chars) + soft count (2 chars) + 3 quadlets (12 chars)
+        assert_eq!(m.qb64.as_ref().unwrap().len(), 2 + 2 + 12);
+    }
+
+    #[test]
+    fn test_from_raw_var_pads_unaligned_length_round_trips() {
+        // 10 bytes needs 2 lead bytes to reach a quadlet boundary, so
+        // `from_raw_var` should pick the `BytesL2` ("4C") sibling code.
+        let raw = vec![1u8; 10];
+        let m = Matter::from_raw_var(raw.clone(), MatterCodex::Bytes.code()).unwrap();
+        assert_eq!(m.code, MatterCodex::BytesL2.code());
+        assert!(m.qb64.as_ref().unwrap().starts_with("4C"));
+
+        let back = Matter::from_qb64(m.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+    }
+
+    #[test]
+    fn test_from_raw_var_pads_single_lead_byte_round_trips() {
+        // 11 bytes needs 1 lead byte to reach a quadlet boundary, so
+        // `from_raw_var` should pick the `BytesL1` ("4B") sibling code.
+        let raw = vec![2u8; 11];
+        let m = Matter::from_raw_var(raw.clone(), MatterCodex::Bytes.code()).unwrap();
+        assert_eq!(m.code, MatterCodex::BytesL1.code());
+        assert!(m.qb64.as_ref().unwrap().starts_with("4B"));
+
+        let back = Matter::from_qb64(m.qb64.as_ref().unwrap()).unwrap();
+        assert_eq!(back.raw.unwrap(), raw);
+    }
+
+    #[test]
+    fn test_from_raw_var_rejects_fixed_code() {
+        let err = Matter::from_raw_var(vec![0u8; 32], MatterCodex::Ed25519.code()).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_raw_rejects_variable_code() {
+        let err = Matter::from_raw(vec![0u8; 9], MatterCodex::Bytes.code()).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_truncated_variable_material() {
+        let raw = vec![5u8; 9];
+        let m = Matter::from_raw_var(raw, MatterCodex::Bytes.code()).unwrap();
+        let qb64 = m.qb64.unwrap();
+        let err = Matter::from_qb64(&qb64[..qb64.len() - 1]).unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
+    }
+
+    #[test]
+    fn test_from_qb64_rejects_multibyte_char_in_variable_soft_part_instead_of_panicking() {
+        // "4A" has hs=2, ss=2; a multi-byte char right after the code lands
+        // in the soft count span and must error, not panic.
+        let err = Matter::from_qb64("4A€rest of the stream").unwrap_err();
+        assert!(matches!(err, crate::error::Error::MatterError(_)));
     }
 }
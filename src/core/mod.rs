@@ -0,0 +1,5 @@
+pub mod counter;
+pub mod keys;
+pub mod matter;
+pub mod parser;
+pub mod sizage;
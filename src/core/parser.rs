@@ -0,0 +1,283 @@
+use crate::core::counter::Counter;
+use crate::core::matter::{qb64_frame_len, Matter};
+use crate::error::Error;
+
+/// The encoding a CESR stream is "cold-started" in, identified from the
+/// tritet (top three bits) of its leading byte so a `Parser` can dispatch
+/// without being told the encoding up front.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Cold {
+    /// CESR qb64 text: the leading byte is a Base64URL code character.
+    Text,
+    /// CESR qb2 binary: the leading byte is an op/count code tritet.
+    Binary,
+    Json,
+    Cbor,
+    MsgPack,
+}
+
+/// Identifies the `Cold` encoding of a stream from its leading byte.
+///
+/// ASCII-range bytes are either a CESR text code character or JSON's `{`.
+/// High-bit-set bytes are classified by their tritet: CBOR's map major
+/// type occupies `0xA0..=0xBF` (tritet `0b101`), MessagePack's fixmap and
+/// fixarray occupy `0x80..=0x9F` (tritet `0b100`), and the remaining
+/// tritets (`0b110`, `0b111`) are reserved for CESR qb2 op/count codes.
+pub fn sniff(byte: u8) -> Result<Cold, Error> {
+    if byte == b'-' || byte == b'_' || byte.is_ascii_alphanumeric() {
+        return Ok(Cold::Text);
+    }
+    if byte == b'{' {
+        return Ok(Cold::Json);
+    }
+
+    match byte >> 5 {
+        0b101 => Ok(Cold::Cbor),
+        0b100 => Ok(Cold::MsgPack),
+        0b110 | 0b111 => Ok(Cold::Binary),
+        _ => Err(Error::MatterError(format!(
+            "unrecognized cold-start byte 0x{byte:02x}"
+        ))),
+    }
+}
+
+/// Scans a byte buffer for successive CESR primitives, auto-detecting the
+/// text/binary encoding of each one from its cold-start byte.
+pub struct Parser<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(buf: &'a [u8]) -> Parser<'a> {
+        Parser { buf, pos: 0 }
+    }
+
+    /// Iterates over the primitives remaining in the buffer. Stops (without
+    /// erroring) once the buffer is exhausted; if a primitive's framing
+    /// can't be completed from the bytes on hand, the underlying error is
+    /// yielded without advancing past it, so `remaining()` still points at
+    /// that primitive and a fresh `Parser` over `remaining()` plus newly
+    /// arrived bytes can resume the scan.
+    pub fn items(&mut self) -> Items<'_, 'a> {
+        Items { parser: self }
+    }
+
+    /// The bytes not yet consumed by a completed `items()` pass.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Reads a single count (group) code off the front of the remaining
+    /// buffer, advancing past it. The `count` attachments it frames are not
+    /// consumed here; callers typically follow with `count` calls into
+    /// `items()` to read the group itself.
+    pub fn read_count(&mut self) -> Result<Counter, Error> {
+        let remaining = &self.buf[self.pos..];
+        if remaining.is_empty() {
+            return Err(Error::MatterError("no bytes left to read a count from".into()));
+        }
+        let cold = sniff(remaining[0])?;
+
+        let (counter, consumed) = match cold {
+            Cold::Text => {
+                // Count codes currently frame to a fixed 4 chars; bound the
+                // UTF-8 check to that prefix so a non-UTF-8 tail elsewhere in
+                // the buffer can't fail a perfectly well-formed count code.
+                let frame = &remaining[..remaining.len().min(4)];
+                let text = std::str::from_utf8(frame).map_err(|e| {
+                    Error::MatterError(format!("invalid utf-8 in cold-start text stream: {e}"))
+                })?;
+                Counter::from_qb64(text)?
+            }
+            Cold::Binary => Counter::from_qb2(remaining)?,
+            Cold::Json | Cold::Cbor | Cold::MsgPack => {
+                return Err(Error::MatterError(format!(
+                    "{cold:?} payloads do not carry CESR count codes"
+                )));
+            }
+        };
+
+        self.pos += consumed;
+        Ok(counter)
+    }
+
+    fn advance(&mut self) -> Result<(Matter, usize), Error> {
+        let remaining = &self.buf[self.pos..];
+        let cold = sniff(remaining[0])?;
+
+        let (mut matter, consumed) = match cold {
+            Cold::Text => {
+                // Bound the UTF-8 check to this one primitive's frame so a
+                // later, differently-encoded primitive in the buffer (e.g. a
+                // qb2 binary attachment group) can't fail a well-formed text
+                // primitive that happens to come first.
+                let frame_len = qb64_frame_len(remaining)?;
+                if remaining.len() < frame_len {
+                    return Err(Error::MatterError(format!(
+                        "buffer too short for primitive: need {frame_len} bytes, got {}",
+                        remaining.len()
+                    )));
+                }
+                let text = std::str::from_utf8(&remaining[..frame_len]).map_err(|e| {
+                    Error::MatterError(format!("invalid utf-8 in cold-start text stream: {e}"))
+                })?;
+                let matter = Matter::from_qb64(text)?;
+                let consumed = matter.qb64.as_ref().map_or(0, String::len);
+                (matter, consumed)
+            }
+            Cold::Binary => {
+                let matter = Matter::from_qb2(remaining)?;
+                let consumed = matter.qb2.as_ref().map_or(0, Vec::len);
+                (matter, consumed)
+            }
+            Cold::Json | Cold::Cbor | Cold::MsgPack => {
+                return Err(Error::MatterError(format!(
+                    "{cold:?} payloads are interleaved but not yet decodable by Parser"
+                )));
+            }
+        };
+
+        matter.strip = Some(true);
+        self.pos += consumed;
+        Ok((matter, consumed))
+    }
+}
+
+/// Iterator of `(Matter, bytes_consumed)` produced by [`Parser::items`].
+pub struct Items<'p, 'a> {
+    parser: &'p mut Parser<'a>,
+}
+
+impl<'p, 'a> Iterator for Items<'p, 'a> {
+    type Item = Result<(Matter, usize), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parser.pos >= self.parser.buf.len() {
+            return None;
+        }
+        Some(self.parser.advance())
+    }
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use super::*;
+    use crate::core::matter::MatterCodex;
+
+    #[test]
+    fn test_sniff_text_and_json() {
+        assert_eq!(sniff(b'D').unwrap(), Cold::Text);
+        assert_eq!(sniff(b'-').unwrap(), Cold::Text);
+        assert_eq!(sniff(b'_').unwrap(), Cold::Text);
+        assert_eq!(sniff(b'{').unwrap(), Cold::Json);
+    }
+
+    #[test]
+    fn test_sniff_binary_and_cbor_and_msgpack() {
+        assert_eq!(sniff(0xC0).unwrap(), Cold::Binary);
+        assert_eq!(sniff(0xFF).unwrap(), Cold::Binary);
+        assert_eq!(sniff(0xA0).unwrap(), Cold::Cbor);
+        assert_eq!(sniff(0xBF).unwrap(), Cold::Cbor);
+        assert_eq!(sniff(0x80).unwrap(), Cold::MsgPack);
+        assert_eq!(sniff(0x9F).unwrap(), Cold::MsgPack);
+    }
+
+    #[test]
+    fn test_parser_yields_successive_qb64_primitives() {
+        let a = Matter::from_raw(vec![1u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let b = Matter::from_raw(vec![2u8; 32], MatterCodex::Ed25519N.code()).unwrap();
+        let stream = format!(
+            "{}{}",
+            a.qb64.as_ref().unwrap(),
+            b.qb64.as_ref().unwrap()
+        );
+
+        let mut parser = Parser::new(stream.as_bytes());
+        let items: Vec<_> = parser.items().collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].0.raw, a.raw);
+        assert_eq!(items[0].1, 44);
+        assert_eq!(items[1].0.raw, b.raw);
+        assert_eq!(parser.remaining().len(), 0);
+    }
+
+    #[test]
+    fn test_parser_reads_text_primitive_despite_non_utf8_tail() {
+        let a = Matter::from_raw(vec![1u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let mut stream = a.qb64.as_ref().unwrap().as_bytes().to_vec();
+        // Simulate a binary attachment group following the text primitive;
+        // 0xFF is not valid UTF-8 and must not taint the text primitive that
+        // already parsed ahead of it.
+        stream.push(0xFF);
+        stream.push(0xFF);
+
+        let mut parser = Parser::new(&stream);
+        let (matter, consumed) = parser.items().next().unwrap().unwrap();
+        assert_eq!(matter.raw, a.raw);
+        assert_eq!(consumed, 44);
+    }
+
+    #[test]
+    fn test_parser_marks_items_as_strippable() {
+        let a = Matter::from_raw(vec![7u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let mut parser = Parser::new(a.qb64.as_ref().unwrap().as_bytes());
+        let (matter, _) = parser.items().next().unwrap().unwrap();
+        assert_eq!(matter.strip, Some(true));
+    }
+
+    #[test]
+    fn test_parser_leaves_position_on_incomplete_primitive() {
+        let a = Matter::from_raw(vec![7u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let qb64 = a.qb64.unwrap();
+        let mut parser = Parser::new(&qb64.as_bytes()[..qb64.len() - 1]);
+
+        assert!(parser.items().next().unwrap().is_err());
+        assert_eq!(parser.remaining().len(), qb64.len() - 1);
+    }
+
+    #[test]
+    fn test_parser_empty_buffer_yields_no_items() {
+        let mut parser = Parser::new(&[]);
+        assert!(parser.items().next().is_none());
+    }
+
+    #[test]
+    fn test_parser_reads_count_then_grouped_items() {
+        use crate::core::counter::{Counter, CountCodex};
+
+        let a = Matter::from_raw(vec![1u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let b = Matter::from_raw(vec![2u8; 32], MatterCodex::Ed25519N.code()).unwrap();
+        let counter = Counter::new(CountCodex::ControllerIdxSigs.code(), 2).unwrap();
+        let stream = format!(
+            "{}{}{}",
+            counter.qb64.as_ref().unwrap(),
+            a.qb64.as_ref().unwrap(),
+            b.qb64.as_ref().unwrap()
+        );
+
+        let mut parser = Parser::new(stream.as_bytes());
+        let read = parser.read_count().unwrap();
+        assert_eq!(read.code, CountCodex::ControllerIdxSigs.code());
+        assert_eq!(read.count, 2);
+
+        let items: Vec<_> = parser.items().collect::<Result<_, _>>().unwrap();
+        assert_eq!(items.len(), read.count as usize);
+        assert_eq!(items[0].0.raw, a.raw);
+        assert_eq!(items[1].0.raw, b.raw);
+    }
+
+    #[test]
+    fn test_parser_read_count_rejects_non_count_primitive() {
+        let a = Matter::from_raw(vec![1u8; 32], MatterCodex::Ed25519.code()).unwrap();
+        let mut parser = Parser::new(a.qb64.as_ref().unwrap().as_bytes());
+        assert!(parser.read_count().is_err());
+    }
+
+    #[test]
+    fn test_parser_read_count_rejects_non_char_boundary_instead_of_panicking() {
+        let mut parser = Parser::new(b"-\xe2\x82\xac");
+        assert!(parser.read_count().is_err());
+    }
+}
@@ -0,0 +1,20 @@
+/// Dimensions of a CESR primitive's text/binary framing: `hs`, `ss` and
+/// `fs` in characters, `ls` in bytes.
+///
+/// `hs` is the hard (fixed) part of the code, `ss` is the soft part that
+/// encodes a count for variable-sized material, `fs` is the full size of
+/// the qb64 primitive (code + material), and `ls` is the number of lead
+/// zero bytes prepended to the raw material before it is Base64 encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sizage {
+    pub hs: u32,
+    pub ss: u32,
+    pub fs: u32,
+    pub ls: u32,
+}
+
+impl Sizage {
+    pub fn new(hs: u32, ss: u32, fs: u32, ls: u32) -> Self {
+        Self { hs, ss, fs, ls }
+    }
+}